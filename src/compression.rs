@@ -0,0 +1,90 @@
+//! This module implements on-the-fly decompression of ELF section data, for sections that carry
+//! `SHF_COMPRESSED` data or use the legacy GNU `.zdebug_*`/`ZLIB` convention.
+
+use std::error::Error;
+use std::io::Read;
+
+use crate::header::{Endianness, WordSize};
+use crate::reader::Reader;
+
+/// Decode the data of a section, inflating it if it is compressed. Uncompressed sections pass
+/// through unchanged.
+pub fn decompress(
+    raw: &[u8],
+    name: &str,
+    is_compressed: bool,
+    word_size: WordSize,
+    endianness: Endianness,
+) -> Result<Vec<u8>, Box<dyn Error>> {
+    if is_compressed {
+        return decompress_shf_compressed(raw, word_size, endianness);
+    }
+
+    if name.starts_with(".zdebug") {
+        if let Some(rest) = raw.strip_prefix(b"ZLIB") {
+            if let Some(size_bytes) = rest.get(..8) {
+                let uncompressed_size = u64::from_be_bytes(size_bytes.try_into()?);
+                return inflate_zlib(&rest[8..], uncompressed_size);
+            }
+        }
+    }
+
+    Ok(raw.to_vec())
+}
+
+/// Decode a section whose data begins with an `Elf{32,64}_Chdr` compression header, as indicated
+/// by the `SHF_COMPRESSED` section flag.
+fn decompress_shf_compressed(
+    raw: &[u8],
+    word_size: WordSize,
+    endianness: Endianness,
+) -> Result<Vec<u8>, Box<dyn Error>> {
+    let mut header_reader = Reader::from_bytes(raw);
+    header_reader.endianness = Some(endianness);
+
+    let ch_type = header_reader.u32()?;
+    if let WordSize::Bits64 = word_size {
+        // 64-bit headers have 4 bytes of reserved padding between `ch_type` and `ch_size`.
+        header_reader.bytes::<4>()?;
+    }
+    let uncompressed_size = match word_size {
+        WordSize::Bits32 => header_reader.u32()? as u64,
+        WordSize::Bits64 => header_reader.u64()?,
+    };
+    // `ch_addralign`; not needed to decompress the data.
+    match word_size {
+        WordSize::Bits32 => {
+            header_reader.u32()?;
+        }
+        WordSize::Bits64 => {
+            header_reader.u64()?;
+        }
+    }
+
+    let body_offset = header_reader.position()? as usize;
+    let body = &raw[body_offset..];
+
+    match ch_type {
+        1 => inflate_zlib(body, uncompressed_size),
+        2 => inflate_zstd(body, uncompressed_size),
+        other => Err(format!("unsupported section compression type {other}").into()),
+    }
+}
+
+fn inflate_zlib(data: &[u8], uncompressed_size: u64) -> Result<Vec<u8>, Box<dyn Error>> {
+    let mut decoder = flate2::read::ZlibDecoder::new(data);
+    let mut out = Vec::with_capacity(uncompressed_size as usize);
+    decoder.read_to_end(&mut out)?;
+    if out.len() as u64 != uncompressed_size {
+        return Err("decompressed size did not match the compression header".into());
+    }
+    Ok(out)
+}
+
+fn inflate_zstd(data: &[u8], uncompressed_size: u64) -> Result<Vec<u8>, Box<dyn Error>> {
+    let out = zstd::decode_all(data)?;
+    if out.len() as u64 != uncompressed_size {
+        return Err("decompressed size did not match the compression header".into());
+    }
+    Ok(out)
+}
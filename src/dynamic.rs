@@ -0,0 +1,179 @@
+//! This module implements the ability to read the ELF dynamic section (`.dynamic`/`PT_DYNAMIC`),
+//! which carries the shared-library dependency list and other metadata the dynamic linker needs
+//! at load time.
+
+use std::error::Error;
+use std::io::{Read, Seek};
+
+use crate::header::WordSize;
+use crate::program_header::{ProgramHeader, ProgramType};
+use crate::reader::Reader;
+
+#[derive(Debug)]
+pub struct Dynamic {
+    /// The kind of metadata carried by this entry.
+    ///
+    /// field: `d_tag`
+    pub tag: DynamicTag,
+
+    /// The entry's value; meaning depends on `tag`. For tags that name a string table offset
+    /// (e.g. [`DynamicTag::Needed`]), this is the raw offset before resolution, see
+    /// [`Dynamic::resolve_names`].
+    ///
+    /// field: `d_un.d_val`/`d_un.d_ptr`
+    pub value: u64,
+}
+
+impl Dynamic {
+    /// Read the entries of the `PT_DYNAMIC` segment, stopping at the `DT_NULL` terminator.
+    /// Returns an empty table if the file has no `PT_DYNAMIC` segment (i.e. it isn't dynamically
+    /// linked).
+    pub fn read_table<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        program_headers: &[ProgramHeader],
+        word_size: WordSize,
+    ) -> Result<Vec<Self>, Box<dyn Error>> {
+        let Some(segment) = program_headers
+            .iter()
+            .find(|program_header| program_header.type_ == ProgramType::Dynamic)
+        else {
+            return Ok(Vec::new());
+        };
+
+        reader.seek(segment.offset.as_u64())?;
+
+        let mut table = Vec::new();
+        loop {
+            let entry = Self::read(reader, word_size)?;
+            let is_null = entry.tag == DynamicTag::Null;
+            table.push(entry);
+            if is_null {
+                break;
+            }
+        }
+        Ok(table)
+    }
+
+    /// Resolve every string-valued entry (`DT_NEEDED`, `DT_SONAME`, `DT_RPATH`, `DT_RUNPATH`) in
+    /// `table` into its name, using the string table named by the `DT_STRTAB` entry.
+    #[expect(unused)]
+    pub fn resolve_names<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        table: &[Self],
+        program_headers: &[ProgramHeader],
+    ) -> Result<Vec<String>, Box<dyn Error>> {
+        let Some(strtab) = table.iter().find(|entry| entry.tag == DynamicTag::StrTab) else {
+            return Err("dynamic section has no DT_STRTAB entry".into());
+        };
+        // `DT_STRTAB`'s value is a virtual address, not a file offset, so it has to be translated
+        // via the `PT_LOAD` segment that maps it before we can seek to it.
+        let strtab_offset = vaddr_to_offset(program_headers, strtab.value)?;
+
+        let mut names = Vec::new();
+        for entry in table {
+            if matches!(
+                entry.tag,
+                DynamicTag::Needed | DynamicTag::SoName | DynamicTag::RPath | DynamicTag::RunPath
+            ) {
+                let offset = strtab_offset + entry.value;
+                names.push(reader.with_saved_position(offset, Reader::cstr)?);
+            }
+        }
+        Ok(names)
+    }
+
+    fn read<R: Read + Seek>(reader: &mut Reader<R>, word_size: WordSize) -> Result<Self, Box<dyn Error>> {
+        let tag;
+        let value;
+
+        match word_size {
+            WordSize::Bits32 => {
+                tag = DynamicTag::from(reader.u32()? as u64);
+                value = reader.u32()? as u64;
+            }
+            WordSize::Bits64 => {
+                tag = DynamicTag::from(reader.u64()?);
+                value = reader.u64()?;
+            }
+        }
+
+        Ok(Self { tag, value })
+    }
+}
+
+/// Translate a virtual address into a file offset via the `PT_LOAD` segment that maps it,
+/// applying that segment's `p_offset - p_vaddr` bias.
+fn vaddr_to_offset(program_headers: &[ProgramHeader], vaddr: u64) -> Result<u64, Box<dyn Error>> {
+    program_headers
+        .iter()
+        .filter(|program_header| program_header.type_ == ProgramType::Load)
+        .find(|program_header| {
+            let start = program_header.vaddr.as_u64();
+            let end = start + program_header.file_size.as_u64();
+            (start..end).contains(&vaddr)
+        })
+        .map(|program_header| {
+            let bias = program_header.offset.as_u64() as i64 - program_header.vaddr.as_u64() as i64;
+            (vaddr as i64 + bias) as u64
+        })
+        .ok_or_else(|| "no PT_LOAD segment maps the given virtual address".into())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DynamicTag {
+    Null,
+    Needed,
+    PltRelSz,
+    PltGot,
+    Hash,
+    StrTab,
+    SymTab,
+    Rela,
+    RelaSz,
+    RelaEnt,
+    StrSz,
+    SymEnt,
+    Init,
+    Fini,
+    SoName,
+    RPath,
+    Rel,
+    RelSz,
+    PltRel,
+    JmpRel,
+    RunPath,
+    Flags,
+    GnuHash,
+    Other(u64),
+}
+
+impl From<u64> for DynamicTag {
+    fn from(value: u64) -> Self {
+        match value {
+            0 => Self::Null,
+            1 => Self::Needed,
+            2 => Self::PltRelSz,
+            3 => Self::PltGot,
+            4 => Self::Hash,
+            5 => Self::StrTab,
+            6 => Self::SymTab,
+            7 => Self::Rela,
+            8 => Self::RelaSz,
+            9 => Self::RelaEnt,
+            10 => Self::StrSz,
+            11 => Self::SymEnt,
+            12 => Self::Init,
+            13 => Self::Fini,
+            14 => Self::SoName,
+            15 => Self::RPath,
+            17 => Self::Rel,
+            18 => Self::RelSz,
+            20 => Self::PltRel,
+            23 => Self::JmpRel,
+            29 => Self::RunPath,
+            30 => Self::Flags,
+            0x6fff_fef5 => Self::GnuHash,
+            other => Self::Other(other),
+        }
+    }
+}
@@ -0,0 +1,236 @@
+//! This module implements accelerated dynamic symbol lookup via the SysV `.hash` and GNU
+//! `.gnu.hash` hash tables, mirroring how the dynamic linker resolves symbols without having to
+//! scan the whole symbol table.
+
+use std::error::Error;
+use std::io::{Read, Seek};
+
+use crate::header::WordSize;
+use crate::reader::Reader;
+use crate::section_header::SectionHeader;
+use crate::symbol::Symbol;
+
+/// Accelerated lookup of dynamic symbols via whichever hash table (`.gnu.hash` is preferred over
+/// the older `.hash`) the binary provides.
+pub struct DynamicSymbols<'a> {
+    symbols: &'a [Symbol],
+    table: HashTable,
+}
+
+enum HashTable {
+    Gnu(GnuHashTable),
+    SysV(SysVHashTable),
+}
+
+#[expect(unused)]
+impl<'a> DynamicSymbols<'a> {
+    /// Build a lookup table from whichever hash section is present among `sections`. Returns
+    /// `None` if neither `.gnu.hash` nor `.hash` is present.
+    pub fn new<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        sections: &[SectionHeader],
+        symbols: &'a [Symbol],
+        word_size: WordSize,
+    ) -> Result<Option<Self>, Box<dyn Error>> {
+        if let Some(section) = SectionHeader::by_name(sections, ".gnu.hash") {
+            let table = GnuHashTable::read(reader, section, word_size)?;
+            return Ok(Some(Self {
+                symbols,
+                table: HashTable::Gnu(table),
+            }));
+        }
+
+        if let Some(section) = SectionHeader::by_name(sections, ".hash") {
+            let table = SysVHashTable::read(reader, section)?;
+            return Ok(Some(Self {
+                symbols,
+                table: HashTable::SysV(table),
+            }));
+        }
+
+        Ok(None)
+    }
+
+    /// Look up a dynamic symbol by name.
+    pub fn lookup(&self, name: &str) -> Option<&'a Symbol> {
+        match &self.table {
+            HashTable::Gnu(table) => table.lookup(self.symbols, name),
+            HashTable::SysV(table) => table.lookup(self.symbols, name),
+        }
+    }
+}
+
+/// The classic ELF/SysV `.hash` section layout.
+struct SysVHashTable {
+    buckets: Vec<u32>,
+    chains: Vec<u32>,
+}
+
+impl SysVHashTable {
+    fn read<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        section: &SectionHeader,
+    ) -> Result<Self, Box<dyn Error>> {
+        reader.seek(section.offset.as_u64())?;
+
+        let bucket_count = reader.u32()?;
+        let chain_count = reader.u32()?;
+
+        let mut buckets = Vec::with_capacity(bucket_count as usize);
+        for _ in 0..bucket_count {
+            buckets.push(reader.u32()?);
+        }
+
+        let mut chains = Vec::with_capacity(chain_count as usize);
+        for _ in 0..chain_count {
+            chains.push(reader.u32()?);
+        }
+
+        Ok(Self { buckets, chains })
+    }
+
+    fn lookup<'a>(&self, symbols: &'a [Symbol], name: &str) -> Option<&'a Symbol> {
+        if self.buckets.is_empty() {
+            return None;
+        }
+
+        let hash = sysv_hash(name.as_bytes());
+        let mut index = *self.buckets.get(hash as usize % self.buckets.len())? as usize;
+
+        while index != 0 {
+            if symbols.get(index).is_some_and(|symbol| symbol.name == name) {
+                return symbols.get(index);
+            }
+            index = *self.chains.get(index)? as usize;
+        }
+
+        None
+    }
+}
+
+fn sysv_hash(name: &[u8]) -> u32 {
+    let mut hash: u32 = 0;
+    for &byte in name {
+        hash = (hash << 4).wrapping_add(byte as u32);
+        let high_nibble = hash & 0xf000_0000;
+        if high_nibble != 0 {
+            hash ^= high_nibble >> 24;
+        }
+        hash &= !high_nibble;
+    }
+    hash
+}
+
+/// The GNU `.gnu.hash` section layout, consisting of a Bloom filter (to quickly reject symbols
+/// that definitely aren't present) followed by the bucket/chain arrays used by `.hash`.
+struct GnuHashTable {
+    symbol_offset: u32,
+    bloom_shift: u32,
+    bloom_bits: u64,
+    bloom: Vec<u64>,
+    buckets: Vec<u32>,
+    chains: Vec<u32>,
+}
+
+impl GnuHashTable {
+    fn read<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        section: &SectionHeader,
+        word_size: WordSize,
+    ) -> Result<Self, Box<dyn Error>> {
+        reader.seek(section.offset.as_u64())?;
+
+        let bucket_count = reader.u32()?;
+        let symbol_offset = reader.u32()?;
+        let bloom_size = reader.u32()?;
+        let bloom_shift = reader.u32()?;
+
+        let bloom_bits = match word_size {
+            WordSize::Bits32 => 32,
+            WordSize::Bits64 => 64,
+        };
+
+        let mut bloom = Vec::with_capacity(bloom_size as usize);
+        for _ in 0..bloom_size {
+            let word = match word_size {
+                WordSize::Bits32 => reader.u32()? as u64,
+                WordSize::Bits64 => reader.u64()?,
+            };
+            bloom.push(word);
+        }
+
+        let mut buckets = Vec::with_capacity(bucket_count as usize);
+        for _ in 0..bucket_count {
+            buckets.push(reader.u32()?);
+        }
+
+        // The chain array isn't length-prefixed; it runs from `symoffset` to the end of the
+        // section, so its length falls out of what's left of `sh_size`.
+        let header_bytes = 16u64;
+        let bloom_bytes = bloom_size as u64 * (bloom_bits / 8);
+        let buckets_bytes = bucket_count as u64 * 4;
+        let chain_bytes = section
+            .size
+            .as_u64()
+            .saturating_sub(header_bytes + bloom_bytes + buckets_bytes);
+        let chain_count = chain_bytes / 4;
+
+        let mut chains = Vec::with_capacity(chain_count as usize);
+        for _ in 0..chain_count {
+            chains.push(reader.u32()?);
+        }
+
+        Ok(Self {
+            symbol_offset,
+            bloom_shift,
+            bloom_bits,
+            bloom,
+            buckets,
+            chains,
+        })
+    }
+
+    fn lookup<'a>(&self, symbols: &'a [Symbol], name: &str) -> Option<&'a Symbol> {
+        if self.buckets.is_empty() || self.bloom.is_empty() {
+            return None;
+        }
+
+        let hash = gnu_hash(name.as_bytes()) as u64;
+
+        let word_index = (hash / self.bloom_bits) as usize % self.bloom.len();
+        let mask = (1 << (hash % self.bloom_bits))
+            | (1 << ((hash >> self.bloom_shift) % self.bloom_bits));
+        if self.bloom[word_index] & mask != mask {
+            return None;
+        }
+
+        let mut index = *self.buckets.get(hash as usize % self.buckets.len())?;
+        if index == 0 {
+            return None;
+        }
+
+        loop {
+            let chain_index = index.checked_sub(self.symbol_offset)?;
+            let chain_hash = *self.chains.get(chain_index as usize)?;
+
+            if (hash | 1) == (chain_hash as u64 | 1)
+                && symbols.get(index as usize).is_some_and(|s| s.name == name)
+            {
+                return symbols.get(index as usize);
+            }
+
+            if chain_hash & 1 != 0 {
+                return None;
+            }
+            index += 1;
+        }
+    }
+}
+
+fn gnu_hash(name: &[u8]) -> u32 {
+    let mut hash: u32 = 5381;
+    for &byte in name {
+        hash = hash.wrapping_mul(33).wrapping_add(byte as u32);
+    }
+    hash
+}
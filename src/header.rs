@@ -1,6 +1,7 @@
 //! This module implements the ability to read an ELF file header.
 
 use std::error::Error;
+use std::io::{Read, Seek};
 
 use crate::reader::Reader;
 
@@ -102,7 +103,7 @@ pub struct Header {
 }
 
 impl Header {
-    pub fn read(reader: &mut Reader) -> Result<Self, Box<dyn Error>> {
+    pub fn read<R: Read + Seek>(reader: &mut Reader<R>) -> Result<Self, Box<dyn Error>> {
         let magic_bytes = reader.bytes_dynamic(4)?;
         if magic_bytes.as_slice() != &[0x7F, 0x45, 0x4C, 0x46] {
             return Err("not an ELF file".into());
@@ -234,19 +235,28 @@ impl Header {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug)]
 pub enum WordSize {
     Bits32,
     Bits64,
 }
 
-#[derive(Debug)]
-#[expect(unused)]
+#[derive(Clone, Copy, Debug)]
 pub enum Address {
     Bits32(u32),
     Bits64(u64),
 }
 
+impl Address {
+    /// Widen this address to a `u64`, regardless of the word size it was read at.
+    pub fn as_u64(self) -> u64 {
+        match self {
+            Self::Bits32(value) => value as u64,
+            Self::Bits64(value) => value,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub enum Endianness {
     Big,
@@ -0,0 +1,87 @@
+//! This module implements the ability to read ELF note sections (`.note.*`) and `PT_NOTE`
+//! segments, which carry identity/provenance information such as the GNU build-id.
+
+use std::error::Error;
+use std::io::{Read, Seek};
+
+use crate::reader::Reader;
+
+const NT_GNU_BUILD_ID: u32 = 3;
+
+#[derive(Debug)]
+pub struct Note {
+    /// The name of the note's owner, e.g. `GNU`.
+    ///
+    /// field: `n_name`
+    pub name: String,
+
+    /// The note's type; its meaning is specific to `name`.
+    ///
+    /// field: `n_type`
+    pub type_: u32,
+
+    /// The note's descriptor data; its meaning depends on `name` and `type_`.
+    ///
+    /// field: `n_desc`
+    pub desc: Vec<u8>,
+}
+
+impl Note {
+    /// Read all of the notes packed into a `PT_NOTE` segment or `SHT_NOTE` section spanning
+    /// `size` bytes starting at `offset`.
+    pub fn read_table<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        offset: u64,
+        size: u64,
+    ) -> Result<Vec<Self>, Box<dyn Error>> {
+        reader.seek(offset)?;
+
+        let mut notes = Vec::new();
+        let mut remaining = size;
+        while remaining > 0 {
+            let (note, consumed) = Self::read(reader)?;
+            notes.push(note);
+            remaining = remaining.saturating_sub(consumed);
+        }
+        Ok(notes)
+    }
+
+    /// Find the `GNU` note with type `NT_GNU_BUILD_ID` and return its descriptor as a hex string.
+    #[expect(unused)]
+    pub fn build_id(notes: &[Self]) -> Option<String> {
+        notes
+            .iter()
+            .find(|note| note.name == "GNU" && note.type_ == NT_GNU_BUILD_ID)
+            .map(|note| note.desc.iter().map(|byte| format!("{byte:02x}")).collect())
+    }
+
+    fn read<R: Read + Seek>(reader: &mut Reader<R>) -> Result<(Self, u64), Box<dyn Error>> {
+        let name_size = reader.u32()?;
+        let desc_size = reader.u32()?;
+        let type_ = reader.u32()?;
+
+        let name_bytes = reader.bytes_dynamic(name_size as usize)?;
+        let name = String::from_utf8(
+            name_bytes
+                .into_iter()
+                .take_while(|&byte| byte != 0)
+                .collect(),
+        )?;
+        reader.bytes_dynamic(padding(name_size))?;
+
+        let desc = reader.bytes_dynamic(desc_size as usize)?;
+        reader.bytes_dynamic(padding(desc_size))?;
+
+        let consumed = 12 + align4(name_size) as u64 + align4(desc_size) as u64;
+        Ok((Self { name, type_, desc }, consumed))
+    }
+}
+
+/// Round `size` up to the next multiple of four, per the note format's alignment requirement.
+fn align4(size: u32) -> u32 {
+    size.div_ceil(4) * 4
+}
+
+fn padding(size: u32) -> usize {
+    (align4(size) - size) as usize
+}
@@ -0,0 +1,167 @@
+//! This module implements the ability to read an ELF program header (segment) table.
+
+use std::error::Error;
+use std::io::{Read, Seek};
+
+use crate::header::{Address, Header, WordSize};
+use crate::reader::Reader;
+
+#[derive(Debug)]
+#[expect(unused)]
+pub struct ProgramHeader {
+    /// The kind of segment described by this program header.
+    ///
+    /// field: `p_type`
+    pub type_: ProgramType,
+
+    /// The read/write/execute permissions of the segment.
+    ///
+    /// field: `p_flags`
+    pub flags: ProgramFlags,
+
+    /// Offset of the segment in the file image.
+    ///
+    /// field: `p_offset`
+    pub offset: Address,
+
+    /// Virtual address of the segment in memory.
+    ///
+    /// field: `p_vaddr`
+    pub vaddr: Address,
+
+    /// Physical address of the segment, on systems where relevant.
+    ///
+    /// field: `p_paddr`
+    pub paddr: Address,
+
+    /// Size of the segment in the file image.
+    ///
+    /// field: `p_filesz`
+    pub file_size: Address,
+
+    /// Size of the segment in memory.
+    ///
+    /// field: `p_memsz`
+    pub mem_size: Address,
+
+    /// Alignment of the segment.
+    ///
+    /// field: `p_align`
+    pub align: Address,
+}
+
+impl ProgramHeader {
+    /// Seek to `e_phoff` and read the `e_phnum` entries of the program header table.
+    pub fn read_table<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        header: &Header,
+    ) -> Result<Vec<Self>, Box<dyn Error>> {
+        reader.seek(header.program_header_address.as_u64())?;
+
+        let mut table = Vec::with_capacity(header.program_header_entry_count as usize);
+        for _ in 0..header.program_header_entry_count {
+            table.push(Self::read(reader, header.word_size)?);
+        }
+        Ok(table)
+    }
+
+    fn read<R: Read + Seek>(reader: &mut Reader<R>, word_size: WordSize) -> Result<Self, Box<dyn Error>> {
+        let type_ = ProgramType::from(reader.u32()?);
+
+        let flags;
+        let offset;
+        let vaddr;
+        let paddr;
+        let file_size;
+        let mem_size;
+        let align;
+
+        match word_size {
+            WordSize::Bits32 => {
+                offset = Address::Bits32(reader.u32()?);
+                vaddr = Address::Bits32(reader.u32()?);
+                paddr = Address::Bits32(reader.u32()?);
+                file_size = Address::Bits32(reader.u32()?);
+                mem_size = Address::Bits32(reader.u32()?);
+                flags = ProgramFlags::from_bits(reader.u32()?);
+                align = Address::Bits32(reader.u32()?);
+            }
+            WordSize::Bits64 => {
+                // In 64-bit program headers, `p_flags` comes right after `p_type`.
+                flags = ProgramFlags::from_bits(reader.u32()?);
+                offset = Address::Bits64(reader.u64()?);
+                vaddr = Address::Bits64(reader.u64()?);
+                paddr = Address::Bits64(reader.u64()?);
+                file_size = Address::Bits64(reader.u64()?);
+                mem_size = Address::Bits64(reader.u64()?);
+                align = Address::Bits64(reader.u64()?);
+            }
+        }
+
+        Ok(Self {
+            type_,
+            flags,
+            offset,
+            vaddr,
+            paddr,
+            file_size,
+            mem_size,
+            align,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgramType {
+    Null,
+    Load,
+    Dynamic,
+    Interp,
+    Note,
+    Phdr,
+    Tls,
+    GnuEhFrame,
+    GnuStack,
+    GnuRelro,
+    Other(u32),
+}
+
+impl From<u32> for ProgramType {
+    fn from(value: u32) -> Self {
+        match value {
+            0x0 => Self::Null,
+            0x1 => Self::Load,
+            0x2 => Self::Dynamic,
+            0x3 => Self::Interp,
+            0x4 => Self::Note,
+            0x6 => Self::Phdr,
+            0x7 => Self::Tls,
+            0x6474_e550 => Self::GnuEhFrame,
+            0x6474_e551 => Self::GnuStack,
+            0x6474_e552 => Self::GnuRelro,
+            other => Self::Other(other),
+        }
+    }
+}
+
+/// The read/write/execute permissions of a [`ProgramHeader`] segment.
+///
+/// field: `p_flags`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProgramFlags(u32);
+
+#[expect(unused)]
+impl ProgramFlags {
+    pub const EXECUTE: Self = Self(1 << 0);
+    pub const WRITE: Self = Self(1 << 1);
+    pub const READ: Self = Self(1 << 2);
+
+    fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    /// Whether this set of flags contains all of the flags in `other`.
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
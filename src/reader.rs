@@ -1,11 +1,11 @@
 use std::error::Error;
 use std::fs::File;
-use std::io::{self, BufReader, Read};
+use std::io::{self, BufReader, Cursor, Read, Seek, SeekFrom};
 
 use crate::header::Endianness;
 
-pub struct Reader {
-    inner: BufReader<File>,
+pub struct Reader<R> {
+    inner: BufReader<R>,
 
     /// This represents the endianness of the underlying ELF file.
     ///
@@ -17,14 +17,28 @@ pub struct Reader {
     pub(crate) endianness: Option<Endianness>,
 }
 
-impl Reader {
+impl Reader<File> {
     pub fn new(file: File) -> Self {
         Self {
             inner: BufReader::new(file),
             endianness: None,
         }
     }
+}
 
+impl Reader<Cursor<Vec<u8>>> {
+    /// Build a reader over an in-memory ELF image, e.g. one extracted from an archive, embedded
+    /// in another binary, or mapped into memory by the caller. Accepts anything that derefs to a
+    /// byte slice, including a memory-mapped region.
+    pub fn from_bytes(bytes: impl AsRef<[u8]>) -> Self {
+        Self {
+            inner: BufReader::new(Cursor::new(bytes.as_ref().to_vec())),
+            endianness: None,
+        }
+    }
+}
+
+impl<R: Read + Seek> Reader<R> {
     /// Read `N` bytes; used in situations where a statically sized array is needed.
     pub fn bytes<const N: usize>(&mut self) -> Result<[u8; N], io::Error> {
         let mut bytes = [0; N];
@@ -44,6 +58,47 @@ impl Reader {
         Ok(self.bytes::<1>()?[0])
     }
 
+    /// Seek to an absolute offset from the start of the underlying source.
+    pub fn seek(&mut self, offset: u64) -> Result<(), io::Error> {
+        self.inner.seek(SeekFrom::Start(offset))?;
+        Ok(())
+    }
+
+    /// The current offset from the start of the underlying source.
+    pub fn position(&mut self) -> Result<u64, io::Error> {
+        self.inner.stream_position()
+    }
+
+    /// Seek to `offset`, run `f`, then restore the position the reader was at beforehand.
+    ///
+    /// This is used for short side reads at an absolute offset (e.g. a name in a string table,
+    /// or a section's raw data) made in the middle of parsing a table, without losing our place
+    /// in that table. The tables themselves are read by seeking to them with [`Reader::seek`].
+    pub fn with_saved_position<T>(
+        &mut self,
+        offset: u64,
+        f: impl FnOnce(&mut Self) -> Result<T, Box<dyn Error>>,
+    ) -> Result<T, Box<dyn Error>> {
+        let saved = self.position()?;
+        self.seek(offset)?;
+        let result = f(self);
+        self.seek(saved)?;
+        result
+    }
+
+    /// Read a NUL-terminated string starting at the current position.
+    pub fn cstr(&mut self) -> Result<String, Box<dyn Error>> {
+        let mut bytes = Vec::new();
+        loop {
+            let byte = self.byte()?;
+            if byte == 0 {
+                break;
+            }
+            bytes.push(byte);
+        }
+        Ok(String::from_utf8(bytes)?)
+    }
+
     /// Read two bytes from the file and interpret them as one `u16`.
     pub fn u16(&mut self) -> Result<u16, Box<dyn Error>> {
         let Some(endianness) = self.endianness else {
@@ -0,0 +1,119 @@
+//! This module implements the ability to read ELF relocation sections (`SHT_REL`/`SHT_RELA`),
+//! which describe how the loader should patch addresses (e.g. PLT/GOT entries) at load time.
+
+use std::error::Error;
+use std::io::{Read, Seek};
+
+use crate::header::{Address, WordSize};
+use crate::reader::Reader;
+use crate::section_header::{SectionHeader, SectionType};
+use crate::symbol::Symbol;
+
+#[derive(Debug)]
+#[expect(unused)]
+pub struct Relocation {
+    /// The location to be relocated.
+    ///
+    /// field: `r_offset`
+    pub offset: Address,
+
+    /// Index, within the symbol table named by the relocation section's `sh_link`, of the
+    /// symbol this relocation references.
+    ///
+    /// field: `r_info` (sym)
+    pub symbol_index: u32,
+
+    /// The name of the referenced symbol, resolved from the symbol table named by `sh_link`.
+    pub symbol_name: String,
+
+    /// The relocation type; its meaning is specific to the file's `e_machine`.
+    ///
+    /// field: `r_info` (type)
+    pub type_: u32,
+
+    /// The addend used to compute the relocated value, present only for `SHT_RELA` sections.
+    ///
+    /// field: `r_addend`
+    pub addend: Option<i64>,
+}
+
+impl Relocation {
+    /// Read all of the relocations in `section`, which must be a `REL` or `RELA` section,
+    /// resolving each relocation's symbol against the symbol table named by the section's
+    /// `sh_link`.
+    pub fn read_table<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        section: &SectionHeader,
+        sections: &[SectionHeader],
+        word_size: WordSize,
+    ) -> Result<Vec<Self>, Box<dyn Error>> {
+        let Some(symtab) = sections.get(section.link as usize) else {
+            return Err("sh_link does not refer to a valid symbol table section".into());
+        };
+        let symbols = Symbol::read_table(reader, symtab, sections, word_size)?;
+
+        let has_addend = section.type_ == SectionType::Rela;
+
+        let entry_size = section.entry_size.as_u64();
+        if entry_size == 0 {
+            return Err("relocation section has an entry size of zero".into());
+        }
+        let count = section.size.as_u64() / entry_size;
+
+        reader.seek(section.offset.as_u64())?;
+
+        let mut table = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            table.push(Self::read(reader, word_size, has_addend, &symbols)?);
+        }
+        Ok(table)
+    }
+
+    fn read<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        word_size: WordSize,
+        has_addend: bool,
+        symbols: &[Symbol],
+    ) -> Result<Self, Box<dyn Error>> {
+        let offset;
+        let info;
+
+        match word_size {
+            WordSize::Bits32 => {
+                offset = Address::Bits32(reader.u32()?);
+                info = reader.u32()? as u64;
+            }
+            WordSize::Bits64 => {
+                offset = Address::Bits64(reader.u64()?);
+                info = reader.u64()?;
+            }
+        }
+
+        let (symbol_index, type_) = match word_size {
+            WordSize::Bits32 => ((info >> 8) as u32, (info & 0xff) as u32),
+            WordSize::Bits64 => ((info >> 32) as u32, (info & 0xffff_ffff) as u32),
+        };
+
+        let addend = if has_addend {
+            Some(match word_size {
+                WordSize::Bits32 => reader.u32()? as i32 as i64,
+                WordSize::Bits64 => reader.u64()? as i64,
+            })
+        } else {
+            None
+        };
+
+        let symbol_name = symbols
+            .get(symbol_index as usize)
+            .map(|symbol| symbol.name.clone())
+            .unwrap_or_default();
+
+        Ok(Self {
+            offset,
+            symbol_index,
+            symbol_name,
+            type_,
+            addend,
+        })
+    }
+}
@@ -0,0 +1,265 @@
+//! This module implements the ability to read an ELF section header table.
+
+use std::error::Error;
+use std::io::{Read, Seek};
+
+use crate::compression;
+use crate::header::{Address, Header, WordSize};
+use crate::reader::Reader;
+
+#[derive(Debug)]
+#[expect(unused)]
+pub struct SectionHeader {
+    /// Offset of the section's name in the section header string table.
+    ///
+    /// field: `sh_name`
+    pub name_offset: u32,
+
+    /// The human-readable name of this section, resolved from [`Header::section_header_name_entry_idx`].
+    ///
+    /// e.g. `.text`, `.data`
+    pub name: String,
+
+    /// The kind of data stored in this section.
+    ///
+    /// field: `sh_type`
+    pub type_: SectionType,
+
+    /// Attribute flags for this section.
+    ///
+    /// field: `sh_flags`
+    pub flags: SectionFlags,
+
+    /// The virtual address of this section in memory, if it is loaded.
+    ///
+    /// field: `sh_addr`
+    pub addr: Address,
+
+    /// Offset of this section's data in the file image.
+    ///
+    /// field: `sh_offset`
+    pub offset: Address,
+
+    /// Size of this section's data, in bytes.
+    ///
+    /// field: `sh_size`
+    pub size: Address,
+
+    /// Section index of an associated section, meaning depends on `sh_type`.
+    ///
+    /// field: `sh_link`
+    pub link: u32,
+
+    /// Extra information about the section, meaning depends on `sh_type`.
+    ///
+    /// field: `sh_info`
+    pub info: u32,
+
+    /// Required alignment of the section.
+    ///
+    /// field: `sh_addralign`
+    pub addr_align: Address,
+
+    /// Size of each entry for sections that hold a fixed-size entry table.
+    ///
+    /// field: `sh_entsize`
+    pub entry_size: Address,
+}
+
+impl SectionHeader {
+    /// Seek to `e_shoff` and read the `e_shnum` entries of the section header table, resolving
+    /// each section's name along the way.
+    pub fn read_table<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        header: &Header,
+    ) -> Result<Vec<Self>, Box<dyn Error>> {
+        reader.seek(header.section_header_address.as_u64())?;
+
+        let mut table = Vec::with_capacity(header.section_header_entry_count as usize);
+        for _ in 0..header.section_header_entry_count {
+            table.push(Self::read(reader, header.word_size)?);
+        }
+
+        Self::resolve_names(reader, header, &mut table)?;
+
+        Ok(table)
+    }
+
+    /// Find the section with the given name.
+    pub fn by_name<'a>(table: &'a [Self], name: &str) -> Option<&'a Self> {
+        table.iter().find(|section| section.name == name)
+    }
+
+    /// Read this section's data, transparently decompressing it if it is compressed (either via
+    /// `SHF_COMPRESSED`, or the legacy GNU `.zdebug_*` convention). Uncompressed sections are
+    /// returned unchanged.
+    #[expect(unused)]
+    pub fn decompressed_data<R: Read + Seek>(
+        &self,
+        reader: &mut Reader<R>,
+        word_size: WordSize,
+    ) -> Result<Vec<u8>, Box<dyn Error>> {
+        let Some(endianness) = reader.endianness else {
+            return Err(
+                "tried to decompress section data before endianness was defined, this is a bug!"
+                    .into(),
+            );
+        };
+
+        let offset = self.offset.as_u64();
+        let size = self.size.as_u64() as usize;
+        let raw = reader.with_saved_position(offset, |reader| {
+            reader.bytes_dynamic(size).map_err(Into::into)
+        })?;
+
+        compression::decompress(
+            &raw,
+            &self.name,
+            self.flags.contains(SectionFlags::COMPRESSED),
+            word_size,
+            endianness,
+        )
+    }
+
+    fn read<R: Read + Seek>(reader: &mut Reader<R>, word_size: WordSize) -> Result<Self, Box<dyn Error>> {
+        let name_offset = reader.u32()?;
+        let type_ = SectionType::from(reader.u32()?);
+
+        let flags;
+        let addr;
+        let offset;
+        let size;
+
+        match word_size {
+            WordSize::Bits32 => {
+                flags = SectionFlags::from_bits(reader.u32()? as u64);
+                addr = Address::Bits32(reader.u32()?);
+                offset = Address::Bits32(reader.u32()?);
+                size = Address::Bits32(reader.u32()?);
+            }
+            WordSize::Bits64 => {
+                flags = SectionFlags::from_bits(reader.u64()?);
+                addr = Address::Bits64(reader.u64()?);
+                offset = Address::Bits64(reader.u64()?);
+                size = Address::Bits64(reader.u64()?);
+            }
+        }
+
+        let link = reader.u32()?;
+        let info = reader.u32()?;
+
+        let addr_align;
+        let entry_size;
+
+        match word_size {
+            WordSize::Bits32 => {
+                addr_align = Address::Bits32(reader.u32()?);
+                entry_size = Address::Bits32(reader.u32()?);
+            }
+            WordSize::Bits64 => {
+                addr_align = Address::Bits64(reader.u64()?);
+                entry_size = Address::Bits64(reader.u64()?);
+            }
+        }
+
+        Ok(Self {
+            name_offset,
+            // Resolved afterwards, once the string table section itself has been read.
+            name: String::new(),
+            type_,
+            flags,
+            addr,
+            offset,
+            size,
+            link,
+            info,
+            addr_align,
+            entry_size,
+        })
+    }
+
+    fn resolve_names<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        header: &Header,
+        table: &mut [Self],
+    ) -> Result<(), Box<dyn Error>> {
+        let Some(strtab) = table.get(header.section_header_name_entry_idx as usize) else {
+            return Err("e_shstrndx does not refer to a valid section".into());
+        };
+        let strtab_offset = strtab.offset.as_u64();
+
+        for section in table.iter_mut() {
+            let offset = strtab_offset + section.name_offset as u64;
+            section.name = reader.with_saved_position(offset, Reader::cstr)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SectionType {
+    Progbits,
+    Symtab,
+    Strtab,
+    Rela,
+    Hash,
+    Dynamic,
+    Note,
+    Nobits,
+    Rel,
+    Dynsym,
+    InitArray,
+    GnuHash,
+    Other(u32),
+}
+
+impl From<u32> for SectionType {
+    fn from(value: u32) -> Self {
+        match value {
+            0x1 => Self::Progbits,
+            0x2 => Self::Symtab,
+            0x3 => Self::Strtab,
+            0x4 => Self::Rela,
+            0x5 => Self::Hash,
+            0x6 => Self::Dynamic,
+            0x7 => Self::Note,
+            0x8 => Self::Nobits,
+            0x9 => Self::Rel,
+            0xB => Self::Dynsym,
+            0xE => Self::InitArray,
+            0x6fff_fff6 => Self::GnuHash,
+            other => Self::Other(other),
+        }
+    }
+}
+
+/// Attribute flags of a [`SectionHeader`].
+///
+/// field: `sh_flags`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SectionFlags(u64);
+
+#[expect(unused)]
+impl SectionFlags {
+    pub const WRITE: Self = Self(0x1);
+    pub const ALLOC: Self = Self(0x2);
+    pub const EXECINSTR: Self = Self(0x4);
+    pub const MERGE: Self = Self(0x10);
+    pub const STRINGS: Self = Self(0x20);
+    pub const INFO_LINK: Self = Self(0x40);
+    pub const LINK_ORDER: Self = Self(0x80);
+    pub const OS_NONCONFORMING: Self = Self(0x100);
+    pub const GROUP: Self = Self(0x200);
+    pub const TLS: Self = Self(0x400);
+    pub const COMPRESSED: Self = Self(0x800);
+
+    fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    /// Whether this set of flags contains all of the flags in `other`.
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
@@ -0,0 +1,190 @@
+//! This module implements the ability to read ELF symbol tables (`.symtab`/`.dynsym`).
+
+use std::error::Error;
+use std::io::{Read, Seek};
+
+use crate::header::{Address, WordSize};
+use crate::reader::Reader;
+use crate::section_header::SectionHeader;
+
+#[derive(Debug)]
+#[expect(unused)]
+pub struct Symbol {
+    /// The symbol's name, resolved from the string table named by the symbol section's `sh_link`.
+    ///
+    /// field: `st_name`
+    pub name: String,
+
+    /// The symbol's binding, i.e. its linkage/scoping behavior.
+    ///
+    /// field: `st_info` (high nibble)
+    pub binding: SymbolBinding,
+
+    /// What kind of entity this symbol represents.
+    ///
+    /// field: `st_info` (low nibble)
+    pub type_: SymbolType,
+
+    /// The symbol's visibility.
+    ///
+    /// field: `st_other`
+    pub visibility: SymbolVisibility,
+
+    /// The section this symbol is defined in, or a reserved index (e.g. undefined, absolute).
+    ///
+    /// field: `st_shndx`
+    pub section_index: u16,
+
+    /// The symbol's value; meaning depends on context (e.g. an address for a function symbol).
+    ///
+    /// field: `st_value`
+    pub value: Address,
+
+    /// Size of the object, or zero if the size is unknown or irrelevant.
+    ///
+    /// field: `st_size`
+    pub size: Address,
+}
+
+impl Symbol {
+    /// Read all of the symbols in `section`, which must be a `SYMTAB` or `DYNSYM` section,
+    /// resolving each symbol's name against the string table named by the section's `sh_link`.
+    pub fn read_table<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        section: &SectionHeader,
+        sections: &[SectionHeader],
+        word_size: WordSize,
+    ) -> Result<Vec<Self>, Box<dyn Error>> {
+        let Some(strtab) = sections.get(section.link as usize) else {
+            return Err("sh_link does not refer to a valid string table section".into());
+        };
+        let strtab_offset = strtab.offset.as_u64();
+
+        let section_offset = section.offset.as_u64();
+        let section_size = section.size.as_u64();
+        let entry_size = section.entry_size.as_u64();
+        if entry_size == 0 {
+            return Err("symbol table section has an entry size of zero".into());
+        }
+        let count = section_size / entry_size;
+
+        reader.seek(section_offset)?;
+
+        let mut table = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            table.push(Self::read(reader, word_size, strtab_offset)?);
+        }
+        Ok(table)
+    }
+
+    fn read<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        word_size: WordSize,
+        strtab_offset: u64,
+    ) -> Result<Self, Box<dyn Error>> {
+        let name_offset;
+        let info;
+        let other;
+        let section_index;
+        let value;
+        let size;
+
+        match word_size {
+            WordSize::Bits32 => {
+                name_offset = reader.u32()?;
+                value = Address::Bits32(reader.u32()?);
+                size = Address::Bits32(reader.u32()?);
+                info = reader.byte()?;
+                other = reader.byte()?;
+                section_index = reader.u16()?;
+            }
+            WordSize::Bits64 => {
+                name_offset = reader.u32()?;
+                info = reader.byte()?;
+                other = reader.byte()?;
+                section_index = reader.u16()?;
+                value = Address::Bits64(reader.u64()?);
+                size = Address::Bits64(reader.u64()?);
+            }
+        }
+
+        let name_offset = strtab_offset + name_offset as u64;
+        let name = reader.with_saved_position(name_offset, Reader::cstr)?;
+
+        Ok(Self {
+            name,
+            binding: SymbolBinding::from(info >> 4),
+            type_: SymbolType::from(info & 0xF),
+            visibility: SymbolVisibility::from(other & 0x3),
+            section_index,
+            value,
+            size,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolBinding {
+    Local,
+    Global,
+    Weak,
+    Other(u8),
+}
+
+impl From<u8> for SymbolBinding {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Local,
+            1 => Self::Global,
+            2 => Self::Weak,
+            other => Self::Other(other),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolType {
+    NoType,
+    Object,
+    Func,
+    Section,
+    File,
+    Common,
+    Tls,
+    Other(u8),
+}
+
+impl From<u8> for SymbolType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::NoType,
+            1 => Self::Object,
+            2 => Self::Func,
+            3 => Self::Section,
+            4 => Self::File,
+            5 => Self::Common,
+            6 => Self::Tls,
+            other => Self::Other(other),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolVisibility {
+    Default,
+    Internal,
+    Hidden,
+    Protected,
+}
+
+impl From<u8> for SymbolVisibility {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Default,
+            1 => Self::Internal,
+            2 => Self::Hidden,
+            3 => Self::Protected,
+            _ => Self::Default,
+        }
+    }
+}